@@ -0,0 +1,133 @@
+//! Async edge-event stream, gated behind the `tokio` feature.
+//!
+//! Built on [`LineRequest::event_fd`]: the fd is registered with a
+//! [`tokio::io::unix::AsyncFd`] reactor and, on readiness,
+//! `gpiod_line_event_read_fd_multiple` drains up to `READ_BATCH` queued
+//! events per syscall, reading again until the fd would block, so many
+//! lines can be monitored concurrently without a dedicated blocking thread
+//! per line.
+
+use std::collections::VecDeque;
+use std::io;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::io::unix::AsyncFd;
+
+use libgpiod_sys as ffi;
+
+use crate::error::last_os_error;
+use crate::event::EdgeEvent;
+use crate::request::LineRequest;
+use crate::{Error, Result};
+
+/// Maximum number of events drained from the kernel per
+/// `gpiod_line_event_read_fd_multiple` call.
+const READ_BATCH: usize = 16;
+
+struct LineEventFd(RawFd);
+
+impl AsRawFd for LineEventFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+/// `AsyncFd` requires the fd it wraps to already be non-blocking: it only
+/// arms the reactor and waits for readiness, it doesn't make the
+/// subsequent read non-blocking for you. [`LineRequest::event_fd`] hands
+/// back a blocking fd, so without this a spurious or level-triggered
+/// wakeup would read straight through into a blocking
+/// `gpiod_line_event_read_fd_multiple` call and stall the worker thread.
+fn set_nonblocking(fd: RawFd) -> Result<()> {
+    let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+    if flags < 0 {
+        return Err(last_os_error());
+    }
+
+    let ret = unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) };
+    if ret < 0 {
+        return Err(last_os_error());
+    }
+
+    Ok(())
+}
+
+/// An async `Stream` of [`EdgeEvent`]s for a [`LineRequest`] made with edge
+/// detection enabled.
+///
+/// Borrows the request for as long as the stream exists, since the events
+/// it yields are only meaningful while the reservation is held.
+pub struct AsyncEdgeEventStream<'a, 'b> {
+    async_fd: AsyncFd<LineEventFd>,
+    request: &'a LineRequest<'b>,
+    pending: VecDeque<EdgeEvent>,
+}
+
+impl<'a, 'b> AsyncEdgeEventStream<'a, 'b> {
+    /// Register `request`'s event fd with the tokio reactor.
+    pub fn new(request: &'a LineRequest<'b>) -> Result<Self> {
+        let fd = request.event_fd()?;
+        set_nonblocking(fd)?;
+
+        Ok(AsyncEdgeEventStream {
+            async_fd: AsyncFd::new(LineEventFd(fd)).map_err(Error::Gpio)?,
+            request,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// The request this stream is draining events for.
+    pub fn request(&self) -> &LineRequest<'b> {
+        self.request
+    }
+}
+
+impl<'a, 'b> Stream for AsyncEdgeEventStream<'a, 'b> {
+    type Item = Result<EdgeEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(event) = this.pending.pop_front() {
+            return Poll::Ready(Some(Ok(event)));
+        }
+
+        loop {
+            let mut guard = match this.async_fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(Error::Gpio(e)))),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let mut buf: Vec<ffi::gpiod_line_event> = Vec::with_capacity(READ_BATCH);
+            let n = unsafe {
+                ffi::gpiod_line_event_read_fd_multiple(
+                    this.async_fd.as_raw_fd(),
+                    buf.as_mut_ptr(),
+                    READ_BATCH as _,
+                )
+            };
+
+            if n < 0 {
+                let err = last_os_error();
+                if let Error::Gpio(ioerr) = &err {
+                    if ioerr.kind() == io::ErrorKind::WouldBlock {
+                        guard.clear_ready();
+                        continue;
+                    }
+                }
+                return Poll::Ready(Some(Err(err)));
+            }
+
+            unsafe { buf.set_len(n as usize) };
+            this.pending.extend(buf.iter().map(EdgeEvent::from_raw));
+
+            if let Some(event) = this.pending.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+        }
+    }
+}
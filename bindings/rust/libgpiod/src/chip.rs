@@ -0,0 +1,121 @@
+use std::ffi::{CStr, CString};
+use std::io;
+use std::marker::PhantomData;
+
+use libgpiod_sys as ffi;
+
+use crate::error::{last_os_error, Error};
+use crate::line::Line;
+use crate::line_bulk::LineBulk;
+use crate::Result;
+
+/// Owned, RAII handle to a GPIO chip.
+///
+/// Opened by [`Chip::open`]; the chip's refcount is dropped via
+/// `gpiod_chip_unref` when the `Chip` goes out of scope.
+pub struct Chip {
+    pub(crate) ptr: *mut ffi::gpiod_chip,
+}
+
+// The raw pointer is only ever accessed through `&self`/`&mut self`, mirrored
+// by the thread-(un)safety of the underlying C library itself.
+unsafe impl Send for Chip {}
+
+impl Chip {
+    /// Open a GPIO chip by path, e.g. `/dev/gpiochip0`.
+    pub fn open(path: &str) -> Result<Self> {
+        let path = CString::new(path)
+            .map_err(|e| Error::Gpio(io::Error::new(io::ErrorKind::InvalidInput, e)))?;
+
+        let ptr = unsafe { ffi::gpiod_chip_open(path.as_ptr()) };
+        if ptr.is_null() {
+            return Err(last_os_error());
+        }
+
+        Ok(Chip { ptr })
+    }
+
+    /// Name of the chip as represented in the kernel.
+    pub fn name(&self) -> String {
+        unsafe {
+            CStr::from_ptr(ffi::gpiod_chip_get_name(self.ptr))
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Label of the chip as represented in the kernel.
+    pub fn label(&self) -> String {
+        unsafe {
+            CStr::from_ptr(ffi::gpiod_chip_get_label(self.ptr))
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Number of GPIO lines exposed by this chip.
+    pub fn num_lines(&self) -> u32 {
+        unsafe { ffi::gpiod_chip_get_num_lines(self.ptr) }
+    }
+
+    /// Get a single line at the given offset.
+    pub fn get_line(&self, offset: u32) -> Result<Line<'_>> {
+        let ptr = unsafe { ffi::gpiod_chip_get_line(self.ptr, offset) };
+        if ptr.is_null() {
+            return Err(last_os_error());
+        }
+
+        Ok(Line {
+            ptr,
+            _chip: PhantomData,
+        })
+    }
+
+    /// Get a set of lines and store them in a [`LineBulk`].
+    pub fn get_lines(&self, offsets: &[u32]) -> Result<LineBulk<'_>> {
+        let mut offsets = offsets.to_vec();
+        let ptr = unsafe {
+            ffi::gpiod_chip_get_lines(self.ptr, offsets.as_mut_ptr(), offsets.len() as _)
+        };
+        if ptr.is_null() {
+            return Err(last_os_error());
+        }
+
+        Ok(LineBulk {
+            ptr,
+            _chip: PhantomData,
+        })
+    }
+
+    /// Get all lines exposed by this chip.
+    pub fn get_all_lines(&self) -> Result<LineBulk<'_>> {
+        let ptr = unsafe { ffi::gpiod_chip_get_all_lines(self.ptr) };
+        if ptr.is_null() {
+            return Err(last_os_error());
+        }
+
+        Ok(LineBulk {
+            ptr,
+            _chip: PhantomData,
+        })
+    }
+
+    /// Map a line's name to its offset within this chip.
+    pub fn find_line(&self, name: &str) -> Result<u32> {
+        let name = CString::new(name)
+            .map_err(|e| Error::Gpio(io::Error::new(io::ErrorKind::InvalidInput, e)))?;
+
+        let offset = unsafe { ffi::gpiod_chip_find_line(self.ptr, name.as_ptr()) };
+        if offset < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(offset as u32)
+    }
+}
+
+impl Drop for Chip {
+    fn drop(&mut self) {
+        unsafe { ffi::gpiod_chip_unref(self.ptr) };
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::event::ClockSource;
+
+/// Direction a line should be requested with.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Direction {
+    /// Request the line for reading its state.
+    Input,
+    /// Request the line for driving its state.
+    Output,
+}
+
+/// Edge detection requested on a line, if any.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EdgeDetection {
+    /// Don't watch for edge events.
+    None,
+    /// Only watch rising edge events.
+    RisingEdge,
+    /// Only watch falling edge events.
+    FallingEdge,
+    /// Watch both types of events.
+    BothEdges,
+}
+
+/// Internal bias setting.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Bias {
+    /// Leave the bias setting untouched.
+    AsIs,
+    /// Disable the internal bias.
+    Disabled,
+    /// Enable the internal pull-up bias.
+    PullUp,
+    /// Enable the internal pull-down bias.
+    PullDown,
+}
+
+/// Drive setting for an output line.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Drive {
+    /// Drive both high and low (the default).
+    PushPull,
+    /// Drive low, let external circuitry pull high.
+    OpenDrain,
+    /// Drive high, let external circuitry pull low.
+    OpenSource,
+}
+
+/// Per-line settings, combined into a [`LineConfig`] so one request can mix
+/// inputs, outputs and edge-watching lines.
+///
+/// `debounce_period` has no equivalent in the v1 kernel uAPI wrapped by this
+/// crate; it is accepted here so callers can express intent, but a request
+/// in which any line of the bulk has it set always fails to build (see
+/// [`BulkRequest::from_config`](crate::BulkRequest::from_config)).
+#[derive(Copy, Clone, Debug)]
+pub struct LineSettings {
+    pub direction: Direction,
+    pub edge_detection: EdgeDetection,
+    pub bias: Bias,
+    pub drive: Drive,
+    pub active_low: bool,
+    pub output_value: u8,
+    pub debounce_period: Option<Duration>,
+}
+
+impl Default for LineSettings {
+    fn default() -> Self {
+        LineSettings {
+            direction: Direction::Input,
+            edge_detection: EdgeDetection::None,
+            bias: Bias::AsIs,
+            drive: Drive::PushPull,
+            active_low: false,
+            output_value: 0,
+            debounce_period: None,
+        }
+    }
+}
+
+/// Maps line offsets within a bulk to the [`LineSettings`] they should be
+/// requested with.
+///
+/// The v1 uAPI underlying this crate can only apply one direction and one
+/// set of flags to an entire `gpiod_line_request_bulk` call, so a
+/// `LineConfig` whose settings for the lines of one bulk disagree on
+/// anything but `output_value` cannot be submitted as a single request;
+/// entries for offsets outside the bulk are not consulted. See
+/// [`BulkRequest::from_config`](crate::BulkRequest::from_config).
+#[derive(Clone, Debug, Default)]
+pub struct LineConfig {
+    settings: BTreeMap<u32, LineSettings>,
+}
+
+impl LineConfig {
+    /// Create an empty configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the settings for a given line offset.
+    pub fn add(&mut self, offset: u32, settings: LineSettings) -> &mut Self {
+        self.settings.insert(offset, settings);
+        self
+    }
+
+    pub(crate) fn get(&self, offset: u32) -> LineSettings {
+        self.settings.get(&offset).copied().unwrap_or_default()
+    }
+}
+
+/// Request-wide settings that apply regardless of per-line configuration.
+#[derive(Clone, Debug, Default)]
+pub struct RequestConfig {
+    /// Consumer name recorded by the kernel for this request.
+    pub consumer: String,
+    /// Clock edge event timestamps on this request should be interpreted
+    /// against.
+    ///
+    /// [`ClockSource::Realtime`] makes
+    /// [`BulkRequest::from_config`](crate::BulkRequest::from_config) fail
+    /// with [`Error::Unsupported`](crate::Error::Unsupported); see
+    /// [`ClockSource`].
+    pub clock: ClockSource,
+}
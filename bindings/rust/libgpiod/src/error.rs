@@ -0,0 +1,53 @@
+use std::fmt;
+use std::io;
+
+/// Error type returned by this crate's fallible operations.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying C call failed; wraps the OS error built from `errno`.
+    Gpio(io::Error),
+    /// A [`crate::LineConfig`] couldn't be expressed as a single request,
+    /// mirroring the kernel's `E2BIG` "config too complex" response.
+    ConfigTooComplex,
+    /// The operation has no equivalent in the v1 libgpiod C API this crate
+    /// wraps.
+    Unsupported(&'static str),
+    /// An argument passed in by the caller doesn't make sense for the
+    /// operation requested.
+    InvalidArgument(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Gpio(e) => write!(f, "gpio operation failed: {e}"),
+            Error::ConfigTooComplex => {
+                write!(f, "line configuration is too complex for a single request")
+            }
+            Error::Unsupported(what) => write!(f, "unsupported by the v1 libgpiod API: {what}"),
+            Error::InvalidArgument(what) => write!(f, "invalid argument: {what}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Gpio(e) => Some(e),
+            Error::ConfigTooComplex | Error::Unsupported(_) | Error::InvalidArgument(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Gpio(e)
+    }
+}
+
+pub(crate) fn last_os_error() -> Error {
+    Error::Gpio(io::Error::last_os_error())
+}
+
+/// Convenience result alias used throughout this crate.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,151 @@
+use std::os::raw::c_int;
+use std::os::unix::io::RawFd;
+use std::slice;
+use std::time::Duration;
+
+use libgpiod_sys as ffi;
+
+use crate::error::last_os_error;
+use crate::request::LineRequest;
+use crate::Result;
+
+/// Kind of edge detected.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EdgeKind {
+    /// The line transitioned from low to high.
+    Rising,
+    /// The line transitioned from high to low.
+    Falling,
+}
+
+/// Clock source an edge event's timestamp is measured against.
+///
+/// The v1 kernel uAPI wrapped by this crate always timestamps events
+/// against `CLOCK_MONOTONIC`; there is no request flag to select
+/// `CLOCK_REALTIME` as the v2 uAPI offers. [`ClockSource::Realtime`] is kept
+/// here to mirror [`crate::LineSettings::debounce_period`]'s
+/// express-the-intent-anyway shape, but it can't actually be backed: every
+/// request constructor that takes a `ClockSource` rejects it with
+/// [`crate::Error::Unsupported`] rather than silently mislabeling a
+/// monotonic timestamp as wall-clock time.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum ClockSource {
+    /// Timestamps are not tied to wall-clock time; only useful for
+    /// measuring intervals between events.
+    #[default]
+    Monotonic,
+    /// Timestamps are interpreted as time since the Unix epoch.
+    Realtime,
+}
+
+/// A single decoded edge event.
+#[derive(Copy, Clone, Debug)]
+pub struct EdgeEvent {
+    /// Timestamp of the event, as reported by the kernel.
+    pub timestamp: Duration,
+    /// Kind of edge that triggered this event.
+    pub kind: EdgeKind,
+    /// Offset of the line the event occurred on.
+    pub offset: u32,
+    /// Sequence number of this event, for detecting dropped or coalesced
+    /// events.
+    ///
+    /// The v1 `gpiod_line_event` struct carries no sequence number at all
+    /// (unlike the v2 uAPI's per-line and global counters), so this is
+    /// always `0`. It's kept on the type so callers written against a
+    /// sequence-aware API compile against both uAPI generations; it just
+    /// can't be relied on here to detect gaps.
+    pub sequence: u32,
+}
+
+impl EdgeEvent {
+    pub(crate) fn from_raw(event: &ffi::gpiod_line_event) -> Self {
+        let kind = if event.event_type == ffi::GPIOD_LINE_EVENT_RISING_EDGE as _ {
+            EdgeKind::Rising
+        } else {
+            EdgeKind::Falling
+        };
+
+        EdgeEvent {
+            timestamp: Duration::new(event.ts.tv_sec as u64, event.ts.tv_nsec as u32),
+            kind,
+            offset: event.offset as u32,
+            sequence: 0,
+        }
+    }
+}
+
+/// Caller-owned buffer for batched edge event reads.
+///
+/// Wraps a `Vec<gpiod_line_event>` so `gpiod_line_event_read_multiple`/
+/// `gpiod_line_event_read_fd_multiple` have a caller-allocated array to
+/// fill, draining many queued events in one syscall instead of paying the
+/// per-event overhead of `gpiod_line_event_read`.
+pub struct EdgeEventBuffer {
+    events: Vec<ffi::gpiod_line_event>,
+}
+
+impl EdgeEventBuffer {
+    /// Create a buffer that can hold up to `capacity` events per read.
+    pub fn new(capacity: usize) -> Self {
+        EdgeEventBuffer {
+            events: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Maximum number of events this buffer can hold in one read.
+    pub fn capacity(&self) -> usize {
+        self.events.capacity()
+    }
+
+    /// Fill the buffer from a requested line, up to its capacity, and
+    /// iterate over the events that were read.
+    pub fn read(&mut self, request: &LineRequest<'_>) -> Result<EdgeEventIter<'_>> {
+        let n = unsafe {
+            ffi::gpiod_line_event_read_multiple(
+                request.line_ptr(),
+                self.events.as_mut_ptr(),
+                self.events.capacity() as _,
+            )
+        };
+        self.finish_read(n)
+    }
+
+    /// Fill the buffer directly from a raw event file descriptor, up to its
+    /// capacity, and iterate over the events that were read.
+    pub fn read_fd(&mut self, fd: RawFd) -> Result<EdgeEventIter<'_>> {
+        let n = unsafe {
+            ffi::gpiod_line_event_read_fd_multiple(
+                fd as c_int,
+                self.events.as_mut_ptr(),
+                self.events.capacity() as _,
+            )
+        };
+        self.finish_read(n)
+    }
+
+    fn finish_read(&mut self, ret: c_int) -> Result<EdgeEventIter<'_>> {
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        unsafe { self.events.set_len(ret as usize) };
+
+        Ok(EdgeEventIter {
+            inner: self.events.iter(),
+        })
+    }
+}
+
+/// Iterator over the events currently filled into an [`EdgeEventBuffer`].
+pub struct EdgeEventIter<'a> {
+    inner: slice::Iter<'a, ffi::gpiod_line_event>,
+}
+
+impl<'a> Iterator for EdgeEventIter<'a> {
+    type Item = EdgeEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(EdgeEvent::from_raw)
+    }
+}
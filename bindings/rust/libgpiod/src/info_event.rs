@@ -0,0 +1,73 @@
+//! Line-info change notifications.
+//!
+//! The kernel's GPIO chardev has supported watching a chip fd for
+//! line-info-changed notifications (`GPIO_GET_LINEINFO_WATCH_IOCTL`,
+//! `struct gpioline_info_changed`) since Linux 5.7 — this predates, and is
+//! independent of, the v2 uAPI redesign in 5.10. The gap isn't in the
+//! kernel: it's that the legacy libgpiod C API `libgpiod-sys` binds here
+//! never wrapped that ioctl with a `gpiod_*` function. Those wrappers
+//! (`gpiod_chip_watch_line_info` and friends) were only added alongside
+//! libgpiod's v2 API rewrite, which this crate doesn't bind. So
+//! [`Chip::watch_line_info`] always fails with [`Error::Unsupported`] for
+//! lack of an underlying function to call. The types below are defined now
+//! so the API shape is in place and callers can be written against it
+//! ahead of a binding that adds those declarations.
+
+use std::time::Duration;
+
+use crate::chip::Chip;
+use crate::error::Error;
+use crate::Result;
+
+/// What happened to a watched line.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum InfoEventKind {
+    /// The line was requested by some process.
+    Requested,
+    /// The line was released.
+    Released,
+    /// The line's configuration changed while requested.
+    Reconfigured,
+}
+
+/// A single decoded line-info-changed notification.
+#[derive(Copy, Clone, Debug)]
+pub struct InfoEvent {
+    /// What happened to the line.
+    pub kind: InfoEventKind,
+    /// Timestamp of the event, as reported by the kernel.
+    pub timestamp: Duration,
+    /// Offset of the line the event is about.
+    pub line_offset: u32,
+}
+
+/// A watch on a single line's info-changed notifications.
+///
+/// There is no way to actually obtain one: [`Chip::watch_line_info`] always
+/// returns [`Error::Unsupported`], since `libgpiod-sys` has no `gpiod_*`
+/// declaration for the kernel's line-info-watch ioctl to call through.
+/// This type exists so the watch/unwatch shape is already in place for
+/// when those declarations are added.
+pub struct LineInfoWatch<'a> {
+    _chip: &'a Chip,
+}
+
+impl<'a> LineInfoWatch<'a> {
+    /// Stop watching this line.
+    pub fn unwatch(self) -> Result<()> {
+        Err(Error::Unsupported("line-info-changed notifications"))
+    }
+}
+
+impl Chip {
+    /// Watch `offset` for line-info-changed notifications (another process
+    /// requesting, releasing or reconfiguring it).
+    ///
+    /// Always fails with [`Error::Unsupported`]: the kernel ioctl behind
+    /// this (`GPIO_GET_LINEINFO_WATCH_IOCTL`) exists and predates the v2
+    /// uAPI, but `libgpiod-sys` has no `gpiod_*` binding for it to call
+    /// through.
+    pub fn watch_line_info(&self, _offset: u32) -> Result<LineInfoWatch<'_>> {
+        Err(Error::Unsupported("line-info-changed notifications"))
+    }
+}
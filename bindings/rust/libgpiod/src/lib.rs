@@ -0,0 +1,30 @@
+//! Safe, RAII-based wrapper around `libgpiod-sys`.
+//!
+//! This crate is layered over the raw `libgpiod-sys` FFI bindings the same
+//! way the upstream Rust wrapper is layered over `libgpiod-sys` in the v2
+//! tree: `Chip`, `Line` and `LineBulk` replace manual pointer juggling, and
+//! requesting a line or bulk of lines hands back a `LineRequest`/
+//! `BulkRequest` that owns the kernel reservation and releases it on
+//! `Drop`. Callers never need `unsafe` to drive GPIO lines.
+
+#[cfg(feature = "tokio")]
+mod async_stream;
+mod chip;
+mod config;
+mod error;
+mod event;
+mod info_event;
+mod line;
+mod line_bulk;
+mod request;
+
+#[cfg(feature = "tokio")]
+pub use async_stream::AsyncEdgeEventStream;
+pub use chip::Chip;
+pub use config::{Bias, Direction, Drive, EdgeDetection, LineConfig, LineSettings, RequestConfig};
+pub use error::{Error, Result};
+pub use event::{ClockSource, EdgeEvent, EdgeEventBuffer, EdgeEventIter, EdgeKind};
+pub use info_event::{InfoEvent, InfoEventKind, LineInfoWatch};
+pub use line::Line;
+pub use line_bulk::LineBulk;
+pub use request::{BulkRequest, LineRequest, LineRequestBuilder};
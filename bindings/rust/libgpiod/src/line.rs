@@ -0,0 +1,85 @@
+use std::ffi::CStr;
+use std::marker::PhantomData;
+
+use libgpiod_sys as ffi;
+
+use crate::chip::Chip;
+use crate::config::EdgeDetection;
+use crate::event::ClockSource;
+use crate::request::{LineRequest, LineRequestBuilder};
+use crate::Result;
+
+/// A single GPIO line handle, borrowed from the [`Chip`] that owns it.
+///
+/// `Line`s are obtained through [`Chip::get_line`](crate::Chip::get_line),
+/// [`Chip::get_lines`](crate::Chip::get_lines) or
+/// [`Chip::get_all_lines`](crate::Chip::get_all_lines) and stay valid for as
+/// long as the chip they were retrieved from.
+#[derive(Copy, Clone)]
+pub struct Line<'a> {
+    pub(crate) ptr: *mut ffi::gpiod_line,
+    pub(crate) _chip: PhantomData<&'a Chip>,
+}
+
+impl<'a> Line<'a> {
+    /// Offset of this line within its chip.
+    pub fn offset(&self) -> u32 {
+        unsafe { ffi::gpiod_line_offset(self.ptr) }
+    }
+
+    /// Name of the line as represented in the kernel, if any.
+    pub fn name(&self) -> Option<String> {
+        let ptr = unsafe { ffi::gpiod_line_name(self.ptr) };
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+    }
+
+    /// Whether the line is currently in use by this or another process.
+    pub fn is_used(&self) -> bool {
+        unsafe { ffi::gpiod_line_is_used(self.ptr) }
+    }
+
+    /// Reserve this line for reading.
+    pub fn request_input(self, consumer: &str) -> Result<LineRequest<'a>> {
+        LineRequest::input(self, consumer)
+    }
+
+    /// Reserve this line for driving, with the given initial value.
+    pub fn request_output(self, consumer: &str, default_val: u8) -> Result<LineRequest<'a>> {
+        LineRequest::output(self, consumer, default_val)
+    }
+
+    /// Reserve this line for edge event notifications, timestamping its
+    /// events against `CLOCK_MONOTONIC`.
+    pub fn request_edge_events(
+        self,
+        consumer: &str,
+        edge_detection: EdgeDetection,
+    ) -> Result<LineRequest<'a>> {
+        self.request_edge_events_with_clock(consumer, edge_detection, ClockSource::Monotonic)
+    }
+
+    /// Reserve this line for edge event notifications, interpreting its
+    /// timestamps against `clock`.
+    ///
+    /// Fails with [`Error::Unsupported`](crate::Error::Unsupported) when
+    /// `clock` is [`ClockSource::Realtime`].
+    pub fn request_edge_events_with_clock(
+        self,
+        consumer: &str,
+        edge_detection: EdgeDetection,
+        clock: ClockSource,
+    ) -> Result<LineRequest<'a>> {
+        LineRequest::edge_events(self, consumer, edge_detection, clock)
+    }
+
+    /// Start building a request to reserve this line, chaining direction,
+    /// edge-detection, bias, drive and active-low settings before
+    /// [`LineRequestBuilder::submit`].
+    pub fn request(self) -> LineRequestBuilder<'a> {
+        LineRequestBuilder::new(self)
+    }
+}
@@ -0,0 +1,131 @@
+use std::marker::PhantomData;
+use std::ops::ControlFlow;
+use std::os::raw::{c_int, c_void};
+
+use libgpiod_sys as ffi;
+
+use crate::chip::Chip;
+use crate::line::Line;
+use crate::request::BulkRequest;
+use crate::Result;
+
+/// An owned set of [`Line`]s, as returned by
+/// [`Chip::get_lines`](crate::Chip::get_lines) or
+/// [`Chip::get_all_lines`](crate::Chip::get_all_lines).
+///
+/// The underlying `gpiod_line_bulk` container is freed via
+/// `gpiod_line_bulk_free` when the `LineBulk` is dropped. This only frees
+/// the bookkeeping container itself, not the lines it holds, which remain
+/// owned by the [`Chip`].
+pub struct LineBulk<'a> {
+    pub(crate) ptr: *mut ffi::gpiod_line_bulk,
+    pub(crate) _chip: PhantomData<&'a Chip>,
+}
+
+impl<'a> LineBulk<'a> {
+    /// Number of lines held by this bulk object.
+    pub fn num_lines(&self) -> u32 {
+        unsafe { ffi::gpiod_line_bulk_num_lines(self.ptr) }
+    }
+
+    /// Get the line at the given index.
+    pub fn get(&self, index: u32) -> Option<Line<'a>> {
+        let ptr = unsafe { ffi::gpiod_line_bulk_get_line(self.ptr, index) };
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(Line {
+            ptr,
+            _chip: PhantomData,
+        })
+    }
+
+    /// Iterate over the lines held by this bulk object in index order.
+    pub fn iter(&self) -> LineBulkIter<'_, 'a> {
+        LineBulkIter {
+            bulk: self,
+            next: 0,
+        }
+    }
+
+    /// Iterate over all lines held by this bulk object, stopping early if
+    /// `f` returns [`ControlFlow::Break`].
+    ///
+    /// This is a safe adapter over `gpiod_line_bulk_foreach_line`: the
+    /// closure is stashed behind the callback's `user_data` pointer and
+    /// invoked through a trampoline that translates the C callback ABI back
+    /// into a Rust closure call. Prefer [`LineBulk::iter`] unless you
+    /// specifically want early termination without building an `Iterator`
+    /// adapter chain.
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&Line<'a>) -> ControlFlow<()>,
+    {
+        extern "C" fn trampoline<'a, F>(line: *mut ffi::gpiod_line, user_data: *mut c_void) -> c_int
+        where
+            F: FnMut(&Line<'a>) -> ControlFlow<()>,
+        {
+            let f = unsafe { &mut *(user_data as *mut F) };
+            let line = Line {
+                ptr: line,
+                _chip: PhantomData,
+            };
+
+            match f(&line) {
+                ControlFlow::Continue(()) => ffi::GPIOD_LINE_BULK_CB_NEXT as c_int,
+                ControlFlow::Break(()) => ffi::GPIOD_LINE_BULK_CB_STOP as c_int,
+            }
+        }
+
+        unsafe {
+            ffi::gpiod_line_bulk_foreach_line(self.ptr, trampoline::<F>, &mut f as *mut F as *mut c_void);
+        }
+    }
+
+    /// Reserve every line in this bulk together, for reading.
+    pub fn request_input(self, consumer: &str) -> Result<BulkRequest<'a>> {
+        BulkRequest::input(self, consumer)
+    }
+
+    /// Reserve every line in this bulk together, for driving, with the
+    /// given per-line initial values.
+    pub fn request_output(self, consumer: &str, default_vals: &[u8]) -> Result<BulkRequest<'a>> {
+        BulkRequest::output(self, consumer, default_vals)
+    }
+}
+
+impl<'a> Drop for LineBulk<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gpiod_line_bulk_free(self.ptr) };
+    }
+}
+
+impl<'b, 'a> IntoIterator for &'b LineBulk<'a> {
+    type Item = Line<'a>;
+    type IntoIter = LineBulkIter<'b, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the lines of a [`LineBulk`].
+pub struct LineBulkIter<'b, 'a> {
+    bulk: &'b LineBulk<'a>,
+    next: u32,
+}
+
+impl<'b, 'a> Iterator for LineBulkIter<'b, 'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.bulk.num_lines() {
+            return None;
+        }
+
+        let line = self.bulk.get(self.next);
+        self.next += 1;
+        line
+    }
+}
@@ -0,0 +1,674 @@
+use std::ffi::CString;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use libgpiod_sys as ffi;
+
+use crate::config::{Bias, Direction, Drive, EdgeDetection, LineConfig, LineSettings, RequestConfig};
+use crate::error::{last_os_error, Error};
+use crate::event::{ClockSource, EdgeEvent};
+use crate::line::Line;
+use crate::line_bulk::LineBulk;
+use crate::Result;
+
+/// Reject [`ClockSource::Realtime`] up front: no `GPIOD_LINE_REQUEST_FLAG_*`
+/// exists to make the v1 uAPI timestamp edge events against
+/// `CLOCK_REALTIME`, so accepting it here would let `system_time_of` hand
+/// back a wall-clock time for what is actually a monotonic timestamp.
+fn reject_unsupported_clock(clock: ClockSource) -> Result<()> {
+    match clock {
+        ClockSource::Monotonic => Ok(()),
+        ClockSource::Realtime => Err(Error::Unsupported(
+            "CLOCK_REALTIME event timestamps (the v1 uAPI always timestamps against CLOCK_MONOTONIC)",
+        )),
+    }
+}
+
+/// Check that every line in `settings` agrees on everything but
+/// `output_value`, that none of them set a `debounce_period`, and that edge
+/// detection is only enabled on input lines, returning the shared settings
+/// to build a `gpiod_line_request_bulk` call from.
+///
+/// Returns [`Error::ConfigTooComplex`] otherwise, since the v1 uAPI can only
+/// apply one direction and one set of flags to an entire bulk request.
+fn uniform_settings(settings: &[LineSettings]) -> Result<LineSettings> {
+    let first = *settings.first().unwrap_or(&LineSettings::default());
+
+    let uniform = settings.iter().all(|s| {
+        s.direction == first.direction
+            && s.edge_detection == first.edge_detection
+            && s.bias == first.bias
+            && s.drive == first.drive
+            && s.active_low == first.active_low
+            && s.debounce_period.is_none()
+    });
+    if !uniform || first.debounce_period.is_some() {
+        return Err(Error::ConfigTooComplex);
+    }
+
+    if first.edge_detection != EdgeDetection::None && first.direction != Direction::Input {
+        return Err(Error::ConfigTooComplex);
+    }
+
+    Ok(first)
+}
+
+/// `gpiod_line_request_config::request_type` for a uniform set of settings.
+fn request_type_for(settings: &LineSettings) -> std::os::raw::c_int {
+    (match (settings.direction, settings.edge_detection) {
+        (_, EdgeDetection::RisingEdge) => ffi::GPIOD_LINE_REQUEST_EVENT_RISING_EDGE,
+        (_, EdgeDetection::FallingEdge) => ffi::GPIOD_LINE_REQUEST_EVENT_FALLING_EDGE,
+        (_, EdgeDetection::BothEdges) => ffi::GPIOD_LINE_REQUEST_EVENT_BOTH_EDGES,
+        (Direction::Input, EdgeDetection::None) => ffi::GPIOD_LINE_REQUEST_DIRECTION_INPUT,
+        (Direction::Output, EdgeDetection::None) => ffi::GPIOD_LINE_REQUEST_DIRECTION_OUTPUT,
+    }) as std::os::raw::c_int
+}
+
+/// `gpiod_line_request_config::flags` for a uniform set of settings.
+fn flags_for(settings: &LineSettings) -> std::os::raw::c_int {
+    let mut flags = 0;
+    match settings.drive {
+        Drive::PushPull => {}
+        Drive::OpenDrain => flags |= ffi::GPIOD_LINE_REQUEST_FLAG_OPEN_DRAIN as std::os::raw::c_int,
+        Drive::OpenSource => flags |= ffi::GPIOD_LINE_REQUEST_FLAG_OPEN_SOURCE as std::os::raw::c_int,
+    }
+    if settings.active_low {
+        flags |= ffi::GPIOD_LINE_REQUEST_FLAG_ACTIVE_LOW as std::os::raw::c_int;
+    }
+    match settings.bias {
+        Bias::AsIs => {}
+        Bias::Disabled => flags |= ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_DISABLED as std::os::raw::c_int,
+        Bias::PullUp => flags |= ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_PULL_UP as std::os::raw::c_int,
+        Bias::PullDown => flags |= ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_PULL_DOWN as std::os::raw::c_int,
+    }
+    flags
+}
+
+/// An owned reservation of a single [`Line`].
+///
+/// Obtained through [`Line::request_input`](crate::Line::request_input),
+/// [`Line::request_output`](crate::Line::request_output),
+/// [`Line::request_edge_events`](crate::Line::request_edge_events), or the
+/// fluent [`Line::request`](crate::Line::request) builder for any
+/// combination of direction, bias, drive and active-low flags; the
+/// reservation is released via `gpiod_line_release` when the `LineRequest`
+/// is dropped.
+pub struct LineRequest<'a> {
+    line: Line<'a>,
+    clock: ClockSource,
+}
+
+impl<'a> LineRequest<'a> {
+    pub(crate) fn input(line: Line<'a>, consumer: &str) -> Result<Self> {
+        let consumer = CString::new(consumer).map_err(|_| last_os_error())?;
+
+        let ret = unsafe { ffi::gpiod_line_request_input(line.ptr, consumer.as_ptr()) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(LineRequest {
+            line,
+            clock: ClockSource::Monotonic,
+        })
+    }
+
+    pub(crate) fn output(line: Line<'a>, consumer: &str, default_val: u8) -> Result<Self> {
+        let consumer = CString::new(consumer).map_err(|_| last_os_error())?;
+
+        let ret = unsafe {
+            ffi::gpiod_line_request_output(line.ptr, consumer.as_ptr(), default_val as _)
+        };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(LineRequest {
+            line,
+            clock: ClockSource::Monotonic,
+        })
+    }
+
+    pub(crate) fn edge_events(
+        line: Line<'a>,
+        consumer: &str,
+        edge_detection: EdgeDetection,
+        clock: ClockSource,
+    ) -> Result<Self> {
+        reject_unsupported_clock(clock)?;
+
+        let consumer = CString::new(consumer).map_err(|_| last_os_error())?;
+
+        let ret = match edge_detection {
+            EdgeDetection::RisingEdge => unsafe {
+                ffi::gpiod_line_request_rising_edge_events(line.ptr, consumer.as_ptr())
+            },
+            EdgeDetection::FallingEdge => unsafe {
+                ffi::gpiod_line_request_falling_edge_events(line.ptr, consumer.as_ptr())
+            },
+            EdgeDetection::BothEdges => unsafe {
+                ffi::gpiod_line_request_both_edges_events(line.ptr, consumer.as_ptr())
+            },
+            EdgeDetection::None => {
+                return Err(Error::InvalidArgument(
+                    "edge_detection must not be EdgeDetection::None when requesting edge events",
+                ))
+            }
+        };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(LineRequest { line, clock })
+    }
+
+    pub(crate) fn custom(
+        line: Line<'a>,
+        config: &ffi::gpiod_line_request_config,
+        default_val: std::os::raw::c_int,
+        clock: ClockSource,
+    ) -> Result<Self> {
+        let ret = unsafe { ffi::gpiod_line_request(line.ptr, config, default_val) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(LineRequest { line, clock })
+    }
+
+    /// Offset of the requested line.
+    pub fn offset(&self) -> u32 {
+        self.line.offset()
+    }
+
+    /// Clock source this request's edge event timestamps are interpreted
+    /// against.
+    pub fn clock(&self) -> ClockSource {
+        self.clock
+    }
+
+    /// Convenient wall-clock representation of `event`'s timestamp.
+    ///
+    /// Returns `None` when this request's clock is
+    /// [`ClockSource::Monotonic`], since a monotonic timestamp carries no
+    /// wall-clock meaning.
+    pub fn system_time_of(&self, event: &EdgeEvent) -> Option<SystemTime> {
+        match self.clock {
+            ClockSource::Realtime => Some(UNIX_EPOCH + event.timestamp),
+            ClockSource::Monotonic => None,
+        }
+    }
+
+    pub(crate) fn line_ptr(&self) -> *mut ffi::gpiod_line {
+        self.line.ptr
+    }
+
+    /// Block until an edge event is available or `timeout` elapses.
+    ///
+    /// Only meaningful for requests made with edge detection enabled.
+    /// Returns `true` if at least one event is ready to be read (with
+    /// [`EdgeEventBuffer::read`](crate::EdgeEventBuffer::read), or
+    /// [`EdgeEventBuffer::read_fd`](crate::EdgeEventBuffer::read_fd) on
+    /// [`LineRequest::event_fd`]), `false` on timeout.
+    pub fn wait(&self, timeout: Duration) -> Result<bool> {
+        let timeout = ffi::timespec {
+            tv_sec: timeout.as_secs() as _,
+            tv_nsec: timeout.subsec_nanos() as _,
+        };
+
+        match unsafe { ffi::gpiod_line_event_wait(self.line.ptr, &timeout) } {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(last_os_error()),
+        }
+    }
+
+    /// File descriptor that becomes readable when an edge event is queued
+    /// for this line. Only meaningful for requests made with edge
+    /// detection enabled.
+    pub fn event_fd(&self) -> Result<std::os::raw::c_int> {
+        let fd = unsafe { ffi::gpiod_line_event_get_fd(self.line.ptr) };
+        if fd < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(fd)
+    }
+
+    /// Read the current value of the requested line.
+    pub fn get_value(&self) -> Result<u8> {
+        let ret = unsafe { ffi::gpiod_line_get_value(self.line.ptr) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(ret as u8)
+    }
+
+    /// Set the value of the requested line.
+    pub fn set_value(&self, value: u8) -> Result<()> {
+        let ret = unsafe { ffi::gpiod_line_set_value(self.line.ptr, value as _) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Drop for LineRequest<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gpiod_line_release(self.line.ptr) };
+    }
+}
+
+/// An owned reservation of a whole [`LineBulk`].
+///
+/// Obtained through [`LineBulk::request_input`](crate::LineBulk::request_input)
+/// or [`LineBulk::request_output`](crate::LineBulk::request_output); the
+/// reservation is released via `gpiod_line_release_bulk` when the
+/// `BulkRequest` is dropped. Because the lines were requested together,
+/// this is also the only handle through which their values can be read or
+/// written, sidestepping the "undefined behavior if not requested
+/// together" foot-gun of the raw bindings.
+pub struct BulkRequest<'a> {
+    bulk: LineBulk<'a>,
+    clock: ClockSource,
+}
+
+impl<'a> BulkRequest<'a> {
+    pub(crate) fn input(bulk: LineBulk<'a>, consumer: &str) -> Result<Self> {
+        let consumer = CString::new(consumer).map_err(|_| last_os_error())?;
+
+        let ret = unsafe { ffi::gpiod_line_request_bulk_input(bulk.ptr, consumer.as_ptr()) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(BulkRequest {
+            bulk,
+            clock: ClockSource::Monotonic,
+        })
+    }
+
+    pub(crate) fn output(bulk: LineBulk<'a>, consumer: &str, default_vals: &[u8]) -> Result<Self> {
+        if default_vals.len() != bulk.num_lines() as usize {
+            return Err(Error::InvalidArgument(
+                "default_vals must have one entry per line in the bulk",
+            ));
+        }
+
+        let consumer = CString::new(consumer).map_err(|_| last_os_error())?;
+        let default_vals: Vec<_> = default_vals.iter().map(|&v| v as std::os::raw::c_int).collect();
+
+        let ret = unsafe {
+            ffi::gpiod_line_request_bulk_output(bulk.ptr, consumer.as_ptr(), default_vals.as_ptr())
+        };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(BulkRequest {
+            bulk,
+            clock: ClockSource::Monotonic,
+        })
+    }
+
+    /// Number of lines in this request.
+    pub fn num_lines(&self) -> u32 {
+        self.bulk.num_lines()
+    }
+
+    /// Clock source this request's edge event timestamps are interpreted
+    /// against.
+    pub fn clock(&self) -> ClockSource {
+        self.clock
+    }
+
+    /// Convenient wall-clock representation of `event`'s timestamp.
+    ///
+    /// Returns `None` when this request's clock is
+    /// [`ClockSource::Monotonic`], since a monotonic timestamp carries no
+    /// wall-clock meaning.
+    pub fn system_time_of(&self, event: &EdgeEvent) -> Option<SystemTime> {
+        match self.clock {
+            ClockSource::Realtime => Some(UNIX_EPOCH + event.timestamp),
+            ClockSource::Monotonic => None,
+        }
+    }
+
+    /// Build a request from a per-line [`LineConfig`], validating up front
+    /// that it can be expressed as a single `gpiod_line_request_bulk` call.
+    ///
+    /// The v1 uAPI only has one direction, one set of flags, no edge
+    /// detection on output lines, and no debounce support at all per
+    /// request, so a `config` whose entries disagree on anything but
+    /// `output_value`, or that sets a `debounce_period`, is rejected with
+    /// [`Error::ConfigTooComplex`] rather than silently applied to one line
+    /// and ignored for the rest.
+    pub fn from_config(
+        bulk: LineBulk<'a>,
+        request_config: &RequestConfig,
+        line_config: &LineConfig,
+    ) -> Result<Self> {
+        reject_unsupported_clock(request_config.clock)?;
+
+        let settings: Vec<LineSettings> =
+            bulk.iter().map(|line| line_config.get(line.offset())).collect();
+
+        let first = uniform_settings(&settings)?;
+        let request_type = request_type_for(&first);
+        let flags = flags_for(&first);
+
+        let consumer = CString::new(request_config.consumer.as_str())
+            .map_err(|_| last_os_error())?;
+        let default_vals: Vec<std::os::raw::c_int> =
+            settings.iter().map(|s| s.output_value as std::os::raw::c_int).collect();
+
+        let config = ffi::gpiod_line_request_config {
+            consumer: consumer.as_ptr(),
+            request_type,
+            flags,
+        };
+
+        let ret =
+            unsafe { ffi::gpiod_line_request_bulk(bulk.ptr, &config, default_vals.as_ptr()) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(BulkRequest {
+            bulk,
+            clock: request_config.clock,
+        })
+    }
+
+    /// Read the current values of every requested line, in the same order
+    /// they were added to the bulk.
+    pub fn get_values(&self) -> Result<Vec<u8>> {
+        let mut values: Vec<std::os::raw::c_int> = vec![0; self.bulk.num_lines() as usize];
+
+        let ret = unsafe { ffi::gpiod_line_get_value_bulk(self.bulk.ptr, values.as_mut_ptr()) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(values.into_iter().map(|v| v as u8).collect())
+    }
+
+    /// Set the values of every requested line. `values` must have one entry
+    /// per line, in the same order they were added to the bulk.
+    pub fn set_values(&self, values: &[u8]) -> Result<()> {
+        if values.len() != self.bulk.num_lines() as usize {
+            return Err(Error::InvalidArgument(
+                "values must have one entry per line in the bulk",
+            ));
+        }
+
+        let values: Vec<_> = values.iter().map(|&v| v as std::os::raw::c_int).collect();
+
+        let ret = unsafe { ffi::gpiod_line_set_value_bulk(self.bulk.ptr, values.as_ptr()) };
+        if ret < 0 {
+            return Err(last_os_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Drop for BulkRequest<'a> {
+    fn drop(&mut self) {
+        unsafe { ffi::gpiod_line_release_bulk(self.bulk.ptr) };
+    }
+}
+
+/// Default consumer name used when none is set on a [`LineRequestBuilder`].
+const DEFAULT_CONSUMER: &str = "libgpiod-rs";
+
+/// Fluent builder that collapses the direction/edge-detection/bias/drive/
+/// active-low combinations into one type-checked path, dispatching to
+/// `gpiod_line_request` on [`LineRequestBuilder::submit`].
+///
+/// Obtained through [`Line::request`](crate::Line::request).
+pub struct LineRequestBuilder<'a> {
+    line: Line<'a>,
+    request_type: std::os::raw::c_int,
+    flags: std::os::raw::c_int,
+    default_val: std::os::raw::c_int,
+    consumer: Option<CString>,
+    clock: ClockSource,
+}
+
+impl<'a> LineRequestBuilder<'a> {
+    pub(crate) fn new(line: Line<'a>) -> Self {
+        LineRequestBuilder {
+            line,
+            request_type: ffi::GPIOD_LINE_REQUEST_DIRECTION_AS_IS as std::os::raw::c_int,
+            flags: 0,
+            default_val: 0,
+            consumer: None,
+            clock: ClockSource::Monotonic,
+        }
+    }
+
+    /// Select the clock source edge event timestamps should be interpreted
+    /// against, if this request ends up enabling edge detection.
+    ///
+    /// [`LineRequestBuilder::submit`] fails with [`Error::Unsupported`] when
+    /// this is [`ClockSource::Realtime`].
+    pub fn clock(mut self, clock: ClockSource) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Request the line for reading.
+    pub fn input(mut self) -> Self {
+        self.request_type = ffi::GPIOD_LINE_REQUEST_DIRECTION_INPUT as std::os::raw::c_int;
+        self
+    }
+
+    /// Request the line for driving.
+    pub fn output(mut self) -> Self {
+        self.request_type = ffi::GPIOD_LINE_REQUEST_DIRECTION_OUTPUT as std::os::raw::c_int;
+        self
+    }
+
+    /// Request rising edge event notifications.
+    pub fn rising_edge_events(mut self) -> Self {
+        self.request_type = ffi::GPIOD_LINE_REQUEST_EVENT_RISING_EDGE as std::os::raw::c_int;
+        self
+    }
+
+    /// Request falling edge event notifications.
+    pub fn falling_edge_events(mut self) -> Self {
+        self.request_type = ffi::GPIOD_LINE_REQUEST_EVENT_FALLING_EDGE as std::os::raw::c_int;
+        self
+    }
+
+    /// Request both rising and falling edge event notifications.
+    pub fn both_edges_events(mut self) -> Self {
+        self.request_type = ffi::GPIOD_LINE_REQUEST_EVENT_BOTH_EDGES as std::os::raw::c_int;
+        self
+    }
+
+    /// Mark the line as open-drain.
+    pub fn open_drain(mut self) -> Self {
+        self.flags |= ffi::GPIOD_LINE_REQUEST_FLAG_OPEN_DRAIN as std::os::raw::c_int;
+        self
+    }
+
+    /// Mark the line as open-source.
+    pub fn open_source(mut self) -> Self {
+        self.flags |= ffi::GPIOD_LINE_REQUEST_FLAG_OPEN_SOURCE as std::os::raw::c_int;
+        self
+    }
+
+    /// Mark the line as active-low.
+    pub fn active_low(mut self) -> Self {
+        self.flags |= ffi::GPIOD_LINE_REQUEST_FLAG_ACTIVE_LOW as std::os::raw::c_int;
+        self
+    }
+
+    /// Disable the internal bias.
+    pub fn bias_disabled(mut self) -> Self {
+        self.flags |= ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_DISABLED as std::os::raw::c_int;
+        self
+    }
+
+    /// Enable the internal pull-up bias.
+    pub fn bias_pull_up(mut self) -> Self {
+        self.flags |= ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_PULL_UP as std::os::raw::c_int;
+        self
+    }
+
+    /// Enable the internal pull-down bias.
+    pub fn bias_pull_down(mut self) -> Self {
+        self.flags |= ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_PULL_DOWN as std::os::raw::c_int;
+        self
+    }
+
+    /// Set the initial output value.
+    pub fn default_value(mut self, value: u8) -> Self {
+        self.default_val = value as std::os::raw::c_int;
+        self
+    }
+
+    /// Set the consumer name recorded by the kernel for this request.
+    pub fn consumer(mut self, consumer: &str) -> Self {
+        self.consumer = CString::new(consumer).ok();
+        self
+    }
+
+    /// Dispatch the composed request to `gpiod_line_request`.
+    pub fn submit(self) -> Result<LineRequest<'a>> {
+        reject_unsupported_clock(self.clock)?;
+
+        let consumer = self
+            .consumer
+            .unwrap_or_else(|| CString::new(DEFAULT_CONSUMER).unwrap());
+
+        let config = ffi::gpiod_line_request_config {
+            consumer: consumer.as_ptr(),
+            request_type: self.request_type,
+            flags: self.flags,
+        };
+
+        LineRequest::custom(self.line, &config, self.default_val, self.clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(f: impl Fn(&mut LineSettings)) -> LineSettings {
+        let mut s = LineSettings::default();
+        f(&mut s);
+        s
+    }
+
+    #[test]
+    fn uniform_settings_accepts_matching_lines() {
+        let a = settings(|s| s.direction = Direction::Output);
+        let b = settings(|s| s.direction = Direction::Output);
+
+        let first = uniform_settings(&[a, b]).unwrap();
+        assert_eq!(first.direction, Direction::Output);
+    }
+
+    #[test]
+    fn uniform_settings_rejects_divergent_direction() {
+        let a = settings(|s| s.direction = Direction::Input);
+        let b = settings(|s| s.direction = Direction::Output);
+
+        assert!(matches!(
+            uniform_settings(&[a, b]),
+            Err(Error::ConfigTooComplex)
+        ));
+    }
+
+    #[test]
+    fn uniform_settings_rejects_divergent_bias() {
+        let a = settings(|s| s.bias = Bias::PullUp);
+        let b = settings(|s| s.bias = Bias::PullDown);
+
+        assert!(matches!(
+            uniform_settings(&[a, b]),
+            Err(Error::ConfigTooComplex)
+        ));
+    }
+
+    #[test]
+    fn uniform_settings_rejects_any_debounce_period() {
+        let a = settings(|s| s.debounce_period = Some(Duration::from_millis(10)));
+
+        assert!(matches!(
+            uniform_settings(&[a]),
+            Err(Error::ConfigTooComplex)
+        ));
+    }
+
+    #[test]
+    fn request_type_for_edge_detection_overrides_direction() {
+        let input_edge = settings(|s| {
+            s.direction = Direction::Input;
+            s.edge_detection = EdgeDetection::BothEdges;
+        });
+        assert_eq!(
+            request_type_for(&input_edge),
+            ffi::GPIOD_LINE_REQUEST_EVENT_BOTH_EDGES as std::os::raw::c_int
+        );
+
+        let plain_input = settings(|s| s.direction = Direction::Input);
+        assert_eq!(
+            request_type_for(&plain_input),
+            ffi::GPIOD_LINE_REQUEST_DIRECTION_INPUT as std::os::raw::c_int
+        );
+
+        let plain_output = settings(|s| s.direction = Direction::Output);
+        assert_eq!(
+            request_type_for(&plain_output),
+            ffi::GPIOD_LINE_REQUEST_DIRECTION_OUTPUT as std::os::raw::c_int
+        );
+    }
+
+    #[test]
+    fn flags_for_combines_drive_bias_and_active_low() {
+        let s = settings(|s| {
+            s.drive = Drive::OpenDrain;
+            s.bias = Bias::PullUp;
+            s.active_low = true;
+        });
+
+        let expected = ffi::GPIOD_LINE_REQUEST_FLAG_OPEN_DRAIN as std::os::raw::c_int
+            | ffi::GPIOD_LINE_REQUEST_FLAG_ACTIVE_LOW as std::os::raw::c_int
+            | ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_PULL_UP as std::os::raw::c_int;
+        assert_eq!(flags_for(&s), expected);
+    }
+
+    #[test]
+    fn flags_for_push_pull_as_is_not_active_low_is_zero() {
+        let s = settings(|_| {});
+        assert_eq!(flags_for(&s), 0);
+    }
+
+    #[test]
+    fn flags_for_open_source_and_bias_disabled() {
+        let s = settings(|s| {
+            s.drive = Drive::OpenSource;
+            s.bias = Bias::Disabled;
+        });
+
+        let expected = ffi::GPIOD_LINE_REQUEST_FLAG_OPEN_SOURCE as std::os::raw::c_int
+            | ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_DISABLED as std::os::raw::c_int;
+        assert_eq!(flags_for(&s), expected);
+    }
+
+    #[test]
+    fn flags_for_bias_pull_down() {
+        let s = settings(|s| s.bias = Bias::PullDown);
+        assert_eq!(
+            flags_for(&s),
+            ffi::GPIOD_LINE_REQUEST_FLAG_BIAS_PULL_DOWN as std::os::raw::c_int
+        );
+    }
+}